@@ -1,11 +1,16 @@
-use std::path::PathBuf;
+use std::cell::RefCell;
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap};
+use std::path::{Path, PathBuf};
 use std::sync::atomic::{AtomicU64, Ordering};
-use std::sync::Arc;
-use std::time::UNIX_EPOCH;
+use std::sync::{Arc, Mutex};
+use std::time::{SystemTime, UNIX_EPOCH};
 
 use chrono::DateTime;
 use clap::Parser;
+use git2::Repository;
 use ignore::WalkBuilder;
+use openat::{Dir, SimpleType};
 
 /// Find the most recent modification date in a directory tree.
 #[derive(Parser)]
@@ -30,6 +35,367 @@ struct Cli {
     /// Maximum directory depth to traverse
     #[arg(short = 'd', long)]
     max_depth: Option<usize>,
+
+    /// Use the latest commit touching each tracked file instead of its
+    /// filesystem mtime (falls back to mtime for untracked files or when
+    /// no git repository is found)
+    #[arg(long)]
+    git: bool,
+
+    /// Also print the path of the most-recently-modified file
+    #[arg(long)]
+    show_file: bool,
+
+    /// Print the N most recently modified files instead of just the latest
+    #[arg(long, value_name = "N")]
+    top: Option<usize>,
+
+    /// Warn (and exit non-zero) when the winning timestamp is ambiguous: it
+    /// lacks sub-second data or falls in the current wall-clock second, so
+    /// it may be unsafe to use for cache invalidation
+    #[arg(long)]
+    strict: bool,
+
+    /// Output format for the reported timestamp(s)
+    #[arg(long, value_enum, default_value = "human")]
+    format: Format,
+
+    /// Report timestamps in UTC instead of the local timezone
+    #[arg(long)]
+    utc: bool,
+}
+
+/// Output format for the winning timestamp(s), selected with `--format`.
+#[derive(Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+enum Format {
+    /// `YYYY-MM-DD HH:MM:SS`, the tool's original output
+    Human,
+    /// Seconds since the epoch, with fractional nanoseconds
+    Epoch,
+    Rfc3339,
+    /// `{"seconds": .., "nanoseconds": .., "rfc3339": "..", "path": ".."}`
+    Json,
+}
+
+/// A point in time, following the spirit of Mercurial's
+/// `TruncatedTimestamp`: many filesystems (and git) only record whole
+/// seconds, so the "newest" timestamp found can be ambiguous.
+///
+/// Seconds before the epoch (e.g. reproducible-build tooling resetting
+/// mtimes to 0, or tarballs with bogus dates) are clamped to 0 — this tool
+/// only ever needs to find the *newest* file, so pre-epoch precision isn't
+/// worth the risk of wrapping a negative `i64` into a huge `u64`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct Timestamp {
+    seconds: i64,
+    nanoseconds: u32,
+}
+
+impl Timestamp {
+    fn new(seconds: i64, nanoseconds: u32) -> Self {
+        Timestamp { seconds: seconds.max(0), nanoseconds }
+    }
+
+    fn from_nanos_since_epoch(nanos: u64) -> Self {
+        Timestamp::new((nanos / 1_000_000_000) as i64, (nanos % 1_000_000_000) as u32)
+    }
+
+    /// Git commit times never carry sub-second precision.
+    fn from_seconds(seconds: i64) -> Self {
+        Timestamp::new(seconds, 0)
+    }
+
+    fn as_nanos_since_epoch(&self) -> u64 {
+        self.seconds as u64 * 1_000_000_000 + self.nanoseconds as u64
+    }
+
+    /// True when this timestamp can't be trusted to order correctly against
+    /// a file written again in the same second: either it lacks sub-second
+    /// data outright, or its second is still the current wall-clock second
+    /// and so may yet be mutated again before that second elapses.
+    fn is_ambiguous(&self, now_seconds: i64) -> bool {
+        self.nanoseconds == 0 || self.seconds == now_seconds
+    }
+}
+
+impl PartialOrd for Timestamp {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Timestamp {
+    /// Compares at second precision whenever either side lacks sub-second
+    /// data — a bare `nanoseconds == 0` can't be trusted to mean "earlier
+    /// within the second" rather than "unknown".
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        match self.seconds.cmp(&other.seconds) {
+            std::cmp::Ordering::Equal if self.nanoseconds != 0 && other.nanoseconds != 0 => {
+                self.nanoseconds.cmp(&other.nanoseconds)
+            }
+            ordering => ordering,
+        }
+    }
+}
+
+/// A (timestamp, path) pair ordered by timestamp, ties broken by path so
+/// the heap has a total order.
+#[derive(PartialEq, Eq)]
+struct TimedPath {
+    time: Timestamp,
+    path: PathBuf,
+}
+
+impl Ord for TimedPath {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.time.cmp(&other.time).then_with(|| self.path.cmp(&other.path))
+    }
+}
+
+impl PartialOrd for TimedPath {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Tracks the most recently modified file(s) seen so far: lock-free by
+/// default, or a bounded min-heap of the newest `capacity` paths when a
+/// caller needs them (`--show-file` / `--top`).
+enum Tracker {
+    Fast(AtomicU64),
+    Ranked { heap: Mutex<BinaryHeap<Reverse<TimedPath>>>, capacity: usize },
+}
+
+impl Tracker {
+    fn new(capacity: Option<usize>) -> Self {
+        match capacity {
+            None => Tracker::Fast(AtomicU64::new(0)),
+            Some(capacity) => Tracker::Ranked {
+                heap: Mutex::new(BinaryHeap::with_capacity(capacity + 1)),
+                capacity: capacity.max(1),
+            },
+        }
+    }
+
+    fn record(&self, time: Timestamp, path: impl FnOnce() -> PathBuf) {
+        match self {
+            Tracker::Fast(max_nanos) => {
+                max_nanos.fetch_max(time.as_nanos_since_epoch(), Ordering::Relaxed);
+            }
+            Tracker::Ranked { heap, capacity } => {
+                let mut heap = heap.lock().unwrap();
+                if heap.len() < *capacity {
+                    heap.push(Reverse(TimedPath { time, path: path() }));
+                } else if let Some(Reverse(min)) = heap.peek() {
+                    if time > min.time {
+                        heap.pop();
+                        heap.push(Reverse(TimedPath { time, path: path() }));
+                    }
+                }
+            }
+        }
+    }
+
+    /// Returns the top entries, newest first. Empty if nothing was recorded
+    /// or if this is a `Fast` tracker (its result is read via `max_nanos`).
+    fn into_sorted(self) -> Vec<(Timestamp, PathBuf)> {
+        match self {
+            Tracker::Fast(_) => Vec::new(),
+            Tracker::Ranked { heap, .. } => {
+                let mut entries: Vec<_> = heap
+                    .into_inner()
+                    .unwrap()
+                    .into_iter()
+                    .map(|Reverse(tp)| (tp.time, tp.path))
+                    .collect();
+                entries.sort_by(|a, b| b.0.cmp(&a.0).then_with(|| a.1.cmp(&b.1)));
+                entries
+            }
+        }
+    }
+}
+
+/// Maps each tracked file to the timestamp of the last commit that touched
+/// it. Built once (by walking history a single time), so lookups are a
+/// plain map access rather than a fresh revwalk per file, and so the
+/// `git2::Repository` used to build it — not `Sync`, and therefore unusable
+/// from the parallel walker — never has to leave this function.
+struct GitMtimes {
+    workdir: PathBuf,
+    last_touched: HashMap<PathBuf, Timestamp>,
+}
+
+impl GitMtimes {
+    /// Discovers the repository containing `path` and indexes its history.
+    /// Returns `None` if `path` isn't inside a git repository.
+    fn build(path: &Path) -> Option<Self> {
+        let repo = Repository::discover(path).ok()?;
+        let workdir = repo.workdir()?.canonicalize().ok()?;
+
+        let mut revwalk = repo.revwalk().ok()?;
+        revwalk.push_head().ok()?;
+        revwalk.set_sorting(git2::Sort::TIME).ok()?;
+
+        let mut last_touched = HashMap::new();
+        for oid in revwalk.flatten() {
+            let commit = match repo.find_commit(oid) {
+                Ok(c) => c,
+                Err(_) => continue,
+            };
+            let tree = match commit.tree() {
+                Ok(t) => t,
+                Err(_) => continue,
+            };
+            let parent_tree = commit.parent(0).ok().and_then(|p| p.tree().ok());
+
+            let diff = match repo.diff_tree_to_tree(parent_tree.as_ref(), Some(&tree), None) {
+                Ok(d) => d,
+                Err(_) => continue,
+            };
+
+            let time = Timestamp::from_seconds(commit.time().seconds());
+            for delta in diff.deltas() {
+                if let Some(changed_path) = delta.new_file().path() {
+                    last_touched.entry(changed_path.to_path_buf()).or_insert(time);
+                }
+            }
+        }
+
+        Some(GitMtimes { workdir, last_touched })
+    }
+
+    /// Returns the committer time of the newest commit that modified
+    /// `abs_path`, or `None` if the file isn't tracked. Git only records
+    /// commit times to the second, so the result always lacks sub-second
+    /// data.
+    fn last_commit_time(&self, abs_path: &Path) -> Option<Timestamp> {
+        let abs_path = abs_path.canonicalize().ok()?;
+        let rel_path = abs_path.strip_prefix(&self.workdir).ok()?;
+        self.last_touched.get(rel_path).copied()
+    }
+}
+
+/// A walked entry's modification time and whether it's a regular file.
+struct EntryStat {
+    modified: Timestamp,
+    is_file: bool,
+}
+
+/// Caches the last-opened directory fd so siblings reuse it via a relative
+/// `fstatat` lookup. One cache per walker thread; no locking needed.
+struct DirFdCache {
+    cached: RefCell<Option<(PathBuf, Dir)>>,
+}
+
+impl DirFdCache {
+    fn new() -> Self {
+        DirFdCache { cached: RefCell::new(None) }
+    }
+
+    /// `None` on any error, including a TOCTOU unlink race — callers should
+    /// skip just this entry, not abort the directory.
+    ///
+    /// `openat`'s fd-relative lookup always stats the link itself, never its
+    /// target, so when `follow_links` is set and the entry turns out to be a
+    /// symlink we fall back to a plain path-based stat (which does follow).
+    fn stat(&self, entry_path: &Path, follow_links: bool) -> Option<EntryStat> {
+        let parent = entry_path.parent()?;
+        let file_name = entry_path.file_name()?;
+
+        let mut cached = self.cached.borrow_mut();
+        let stale = !matches!(&*cached, Some((cached_parent, _)) if cached_parent == parent);
+        if stale {
+            let dir = Dir::open(parent).ok()?;
+            *cached = Some((parent.to_path_buf(), dir));
+        }
+
+        let (_, dir) = cached.as_ref()?;
+        let meta = dir.metadata(file_name).ok()?;
+
+        if follow_links && meta.simple_type() == SimpleType::Symlink {
+            let target_meta = std::fs::metadata(entry_path).ok()?;
+            let modified = target_meta.modified().ok()?;
+            let nanos = modified.duration_since(UNIX_EPOCH).ok()?.as_nanos() as u64;
+            return Some(EntryStat {
+                modified: Timestamp::from_nanos_since_epoch(nanos),
+                is_file: target_meta.is_file(),
+            });
+        }
+
+        let stat = meta.stat();
+        let modified = Timestamp::new(stat.st_mtime, stat.st_mtime_nsec as u32);
+
+        Some(EntryStat { modified, is_file: meta.simple_type() == SimpleType::File })
+    }
+}
+
+/// Escapes `s` as a JSON string literal, quotes included.
+fn json_escape(s: &str) -> String {
+    let mut out = String::with_capacity(s.len() + 2);
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+/// Renders one winning timestamp (and, when tracked, its path) in the
+/// requested `--format`.
+fn render(time: Timestamp, path: Option<&Path>, format: Format, utc: bool) -> String {
+    let dt = DateTime::from_timestamp(time.seconds, time.nanoseconds).expect("invalid timestamp");
+
+    match format {
+        Format::Human => {
+            let human = if utc {
+                dt.format("%Y-%m-%d %H:%M:%S").to_string()
+            } else {
+                dt.with_timezone(&chrono::Local).format("%Y-%m-%d %H:%M:%S").to_string()
+            };
+            match path {
+                Some(path) => format!("{human}  {}", path.display()),
+                None => human,
+            }
+        }
+        Format::Epoch => {
+            let epoch = format!("{}.{:09}", time.seconds, time.nanoseconds);
+            match path {
+                Some(path) => format!("{epoch}  {}", path.display()),
+                None => epoch,
+            }
+        }
+        Format::Rfc3339 => {
+            let rfc3339 =
+                if utc { dt.to_rfc3339() } else { dt.with_timezone(&chrono::Local).to_rfc3339() };
+            match path {
+                Some(path) => format!("{rfc3339}  {}", path.display()),
+                None => rfc3339,
+            }
+        }
+        Format::Json => {
+            let rfc3339 =
+                if utc { dt.to_rfc3339() } else { dt.with_timezone(&chrono::Local).to_rfc3339() };
+            let path = match path {
+                Some(path) => json_escape(&path.display().to_string()),
+                None => "null".to_string(),
+            };
+            format!(
+                "{{\"seconds\":{},\"nanoseconds\":{},\"rfc3339\":{},\"path\":{}}}",
+                time.seconds,
+                time.nanoseconds,
+                json_escape(&rfc3339),
+                path,
+            )
+        }
+    }
 }
 
 fn main() {
@@ -47,41 +413,286 @@ fn main() {
         builder.max_depth(Some(depth));
     }
 
-    let max_nanos = Arc::new(AtomicU64::new(0));
+    let git_mtimes = if cli.git { GitMtimes::build(&cli.path) } else { None };
+
+    let needs_path = cli.show_file || cli.format == Format::Json;
+    let capacity = cli.top.or(if needs_path { Some(1) } else { None });
+    let tracker = Arc::new(Tracker::new(capacity));
 
     builder.build_parallel().run(|| {
-        let max_nanos = Arc::clone(&max_nanos);
+        let tracker = Arc::clone(&tracker);
+        let git_mtimes = &git_mtimes;
+        let dir_cache = DirFdCache::new();
         Box::new(move |entry| {
             let entry = match entry {
                 Ok(e) => e,
                 Err(_) => return ignore::WalkState::Continue,
             };
 
-            let metadata = match entry.metadata() {
-                Ok(m) => m,
-                Err(_) => return ignore::WalkState::Continue,
+            let stat = match dir_cache.stat(entry.path(), cli.follow_links) {
+                Some(s) => s,
+                None => return ignore::WalkState::Continue,
             };
 
-            if let Ok(modified) = metadata.modified() {
-                if let Ok(duration) = modified.duration_since(UNIX_EPOCH) {
-                    let nanos = duration.as_nanos() as u64;
-                    max_nanos.fetch_max(nanos, Ordering::Relaxed);
-                }
+            // Git doesn't track directories, so under `--git` they'd only ever
+            // contribute their (often checkout-time-fresh) fs mtime — exclude
+            // them entirely rather than let that mtime mix in with commit times.
+            let time = match &git_mtimes {
+                Some(git) if stat.is_file => Some(git.last_commit_time(entry.path()).unwrap_or(stat.modified)),
+                Some(_) => None,
+                None => Some(stat.modified),
+            };
+
+            if let Some(time) = time {
+                tracker.record(time, || entry.path().to_path_buf());
             }
 
             ignore::WalkState::Continue
         })
     });
 
-    let nanos = max_nanos.load(Ordering::Relaxed);
-    if nanos == 0 {
-        eprintln!("No files found");
+    let now_seconds = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs() as i64)
+        .unwrap_or(i64::MAX);
+
+    let newest = match Arc::try_unwrap(tracker).unwrap_or_else(|_| unreachable!("walker has finished")) {
+        Tracker::Fast(max_nanos) => {
+            let nanos = max_nanos.load(Ordering::Relaxed);
+            if nanos == 0 {
+                eprintln!("No files found");
+                std::process::exit(1);
+            }
+            let time = Timestamp::from_nanos_since_epoch(nanos);
+            println!("{}", render(time, None, cli.format, cli.utc));
+            time
+        }
+        tracker @ Tracker::Ranked { .. } => {
+            let entries = tracker.into_sorted();
+            if entries.is_empty() {
+                eprintln!("No files found");
+                std::process::exit(1);
+            }
+            let newest = entries[0].0;
+            for (time, path) in &entries {
+                println!("{}", render(*time, Some(path), cli.format, cli.utc));
+            }
+            newest
+        }
+    };
+
+    if cli.strict && newest.is_ambiguous(now_seconds) {
+        eprintln!(
+            "warning: newest timestamp is ambiguous (no sub-second data, or in the current second) and may be unsafe for cache invalidation"
+        );
         std::process::exit(1);
     }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn json_escape_quotes_and_escapes_special_characters() {
+        assert_eq!(json_escape("plain"), "\"plain\"");
+        assert_eq!(json_escape("a\"b\\c"), "\"a\\\"b\\\\c\"");
+        assert_eq!(json_escape("a\nb"), "\"a\\nb\"");
+    }
+
+    #[test]
+    fn render_json_includes_path_and_rfc3339() {
+        let time = Timestamp::new(0, 0);
+        let out = render(time, Some(Path::new("/tmp/f")), Format::Json, true);
+        assert!(out.contains("\"seconds\":0"));
+        assert!(out.contains("\"path\":\"/tmp/f\""));
+        assert!(out.contains("1970-01-01T00:00:00"));
+    }
+
+    #[test]
+    fn render_json_without_path_is_null() {
+        let time = Timestamp::new(0, 0);
+        let out = render(time, None, Format::Json, true);
+        assert!(out.contains("\"path\":null"));
+    }
+
+    #[test]
+    fn tracker_fast_keeps_only_the_max() {
+        let tracker = Tracker::new(None);
+        tracker.record(Timestamp::new(100, 0), || PathBuf::from("a"));
+        tracker.record(Timestamp::new(50, 0), || PathBuf::from("b"));
+        tracker.record(Timestamp::new(200, 0), || PathBuf::from("c"));
+        match &tracker {
+            Tracker::Fast(max_nanos) => assert_eq!(
+                max_nanos.load(Ordering::Relaxed),
+                Timestamp::new(200, 0).as_nanos_since_epoch()
+            ),
+            Tracker::Ranked { .. } => panic!("expected the lock-free tracker"),
+        }
+        assert!(tracker.into_sorted().is_empty());
+    }
+
+    #[test]
+    fn tracker_ranked_keeps_top_n_newest_first() {
+        let tracker = Tracker::new(Some(2));
+        tracker.record(Timestamp::new(100, 0), || PathBuf::from("a"));
+        tracker.record(Timestamp::new(300, 0), || PathBuf::from("c"));
+        tracker.record(Timestamp::new(200, 0), || PathBuf::from("b"));
+        tracker.record(Timestamp::new(50, 0), || PathBuf::from("d"));
+
+        let entries = tracker.into_sorted();
+        let seconds: Vec<i64> = entries.iter().map(|(t, _)| t.seconds).collect();
+        assert_eq!(seconds, vec![300, 200]);
+    }
+
+    #[test]
+    fn timestamp_clamps_pre_epoch_seconds() {
+        let t = Timestamp::new(-100_000, 0);
+        assert_eq!(t.seconds, 0);
+        // Must not wrap into a huge value when packed for the fast path.
+        assert_eq!(t.as_nanos_since_epoch(), 0);
+    }
 
-    let secs = (nanos / 1_000_000_000) as i64;
-    let nsecs = (nanos % 1_000_000_000) as u32;
-    let dt = DateTime::from_timestamp(secs, nsecs).expect("invalid timestamp");
-    let local = dt.with_timezone(&chrono::Local);
-    println!("{}", local.format("%Y-%m-%d %H:%M:%S"));
+    #[test]
+    fn timestamp_orders_by_second_when_either_side_lacks_subsecond_data() {
+        let ambiguous = Timestamp::new(100, 0);
+        let precise_same_second = Timestamp::new(100, 500);
+        let later_second = Timestamp::new(101, 0);
+
+        assert_eq!(ambiguous.cmp(&precise_same_second), std::cmp::Ordering::Equal);
+        assert!(later_second > ambiguous);
+        assert!(later_second > precise_same_second);
+    }
+
+    #[test]
+    fn timestamp_is_ambiguous_without_subsecond_data_or_in_current_second() {
+        assert!(Timestamp::new(100, 0).is_ambiguous(200));
+        assert!(Timestamp::new(100, 500).is_ambiguous(100));
+        assert!(!Timestamp::new(100, 500).is_ambiguous(200));
+    }
+
+    #[test]
+    fn dir_fd_cache_skips_missing_entries() {
+        let cache = DirFdCache::new();
+        assert!(cache.stat(Path::new("/nonexistent/path/does-not-exist"), false).is_none());
+    }
+
+    #[test]
+    fn dir_fd_cache_reuses_fd_for_siblings_in_same_dir() {
+        let dir = std::env::temp_dir();
+        let a = dir.join("lastmod-rs-test-a");
+        let b = dir.join("lastmod-rs-test-b");
+        std::fs::write(&a, b"a").unwrap();
+        std::fs::write(&b, b"b").unwrap();
+
+        use std::os::unix::io::AsRawFd;
+        let cached_fd = |cache: &DirFdCache| cache.cached.borrow().as_ref().map(|(_, d)| d.as_raw_fd());
+
+        let cache = DirFdCache::new();
+        let stat_a = cache.stat(&a, false).unwrap();
+        let fd_after_a = cached_fd(&cache);
+        let stat_b = cache.stat(&b, false).unwrap();
+        let fd_after_b = cached_fd(&cache);
+
+        let _ = std::fs::remove_file(&a);
+        let _ = std::fs::remove_file(&b);
+
+        assert!(stat_a.is_file);
+        assert!(stat_b.is_file);
+        assert!(fd_after_a.is_some());
+        assert_eq!(fd_after_a, fd_after_b);
+    }
+
+    #[test]
+    fn dir_fd_cache_without_follow_links_reports_symlink_not_file() {
+        let dir = std::env::temp_dir();
+        let target = dir.join("lastmod-rs-test-link-target");
+        let link = dir.join("lastmod-rs-test-link");
+        std::fs::write(&target, b"target").unwrap();
+        let _ = std::fs::remove_file(&link);
+        std::os::unix::fs::symlink(&target, &link).unwrap();
+
+        let cache = DirFdCache::new();
+        let stat = cache.stat(&link, false).unwrap();
+
+        let _ = std::fs::remove_file(&target);
+        let _ = std::fs::remove_file(&link);
+
+        assert!(!stat.is_file);
+    }
+
+    #[test]
+    fn dir_fd_cache_with_follow_links_resolves_symlink_target() {
+        let dir = std::env::temp_dir();
+        let target = dir.join("lastmod-rs-test-followed-target");
+        let link = dir.join("lastmod-rs-test-followed-link");
+        std::fs::write(&target, b"target").unwrap();
+        let _ = std::fs::remove_file(&link);
+        std::os::unix::fs::symlink(&target, &link).unwrap();
+
+        let cache = DirFdCache::new();
+        let stat = cache.stat(&link, true).unwrap();
+
+        let _ = std::fs::remove_file(&target);
+        let _ = std::fs::remove_file(&link);
+
+        assert!(stat.is_file);
+    }
+
+    #[test]
+    fn git_mtimes_finds_last_commit_time_for_tracked_file() {
+        let dir = std::env::temp_dir().join("lastmod-rs-test-git-repo");
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+
+        let repo = Repository::init(&dir).unwrap();
+        let file_path = dir.join("tracked.txt");
+        std::fs::write(&file_path, b"v1").unwrap();
+
+        let mut index = repo.index().unwrap();
+        index.add_path(Path::new("tracked.txt")).unwrap();
+        index.write().unwrap();
+        let tree = repo.find_tree(index.write_tree().unwrap()).unwrap();
+
+        let commit_seconds = 1_000_000_000;
+        let time = git2::Time::new(commit_seconds, 0);
+        let sig = git2::Signature::new("Test", "test@example.com", &time).unwrap();
+        repo.commit(Some("HEAD"), &sig, &sig, "initial", &tree, &[]).unwrap();
+
+        let git_mtimes = GitMtimes::build(&dir).unwrap();
+        let found = git_mtimes.last_commit_time(&file_path).unwrap();
+
+        let _ = std::fs::remove_dir_all(&dir);
+
+        assert_eq!(found, Timestamp::from_seconds(commit_seconds));
+    }
+
+    #[test]
+    fn git_mtimes_returns_none_for_untracked_file() {
+        let dir = std::env::temp_dir().join("lastmod-rs-test-git-repo-untracked");
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+
+        let repo = Repository::init(&dir).unwrap();
+        std::fs::write(dir.join("tracked.txt"), b"v1").unwrap();
+
+        let mut index = repo.index().unwrap();
+        index.add_path(Path::new("tracked.txt")).unwrap();
+        index.write().unwrap();
+        let tree = repo.find_tree(index.write_tree().unwrap()).unwrap();
+
+        let time = git2::Time::new(1_000_000_000, 0);
+        let sig = git2::Signature::new("Test", "test@example.com", &time).unwrap();
+        repo.commit(Some("HEAD"), &sig, &sig, "initial", &tree, &[]).unwrap();
+
+        let untracked_path = dir.join("untracked.txt");
+        std::fs::write(&untracked_path, b"v2").unwrap();
+
+        let git_mtimes = GitMtimes::build(&dir).unwrap();
+        let found = git_mtimes.last_commit_time(&untracked_path);
+
+        let _ = std::fs::remove_dir_all(&dir);
+
+        assert!(found.is_none());
+    }
 }